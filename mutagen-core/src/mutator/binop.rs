@@ -0,0 +1,37 @@
+//! Helpers shared by the binary-operation mutators.
+
+use proc_macro2::TokenStream;
+use quote::quote_spanned;
+use syn::spanned::Spanned;
+use syn::Expr;
+
+/// Borrow both operands for a mutator call, keeping them at the *same*
+/// ref-level so the comparison's trait bound (`PartialEq`/`PartialOrd`) the call
+/// resolves against matches the one the source operator drove.
+///
+/// The mutators compare their arguments by reference, so each operand has to be
+/// turned into a reference. When both operands are already references in the
+/// source (`&x`, `&mut x`) we forward them untouched: that reproduces the exact
+/// types the original comparison used and handles heterogeneous comparisons
+/// such as `&mut x == &y`. Otherwise we wrap *both* sides in `&( .. )`. Wrapping
+/// only one side is unsound - it leaves the operands at different ref-levels,
+/// and via the blanket `impl PartialEq<&B> for &A` that turns compiling code
+/// into non-compiling code (e.g. `x == &y` with `x: &i32`, which the symmetric
+/// double-borrow `&&i32 == &&i32` compiles fine).
+pub(crate) fn borrow_operands(left: &Expr, right: &Expr) -> (TokenStream, TokenStream) {
+    if is_reference(left) && is_reference(right) {
+        (
+            quote_spanned!(left.span()=> #left),
+            quote_spanned!(right.span()=> #right),
+        )
+    } else {
+        (
+            quote_spanned!(left.span()=> &(#left)),
+            quote_spanned!(right.span()=> &(#right)),
+        )
+    }
+}
+
+fn is_reference(e: &Expr) -> bool {
+    matches!(e, Expr::Reference(_))
+}
@@ -0,0 +1,237 @@
+//! Mutator for the logical connectives `&&` and `||`
+//!
+//! Unlike the other binary-operation mutators, the right operand must not be
+//! evaluated eagerly: `a && side_effect()` may only call `side_effect()` when
+//! `a` is `true`. The generated code therefore hands the right operand to the
+//! mutator wrapped in a closure, so that short-circuit evaluation - including
+//! any side effects - is preserved both for the original and for the mutated
+//! operator.
+
+use std::ops::Deref;
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote_spanned;
+use quote::{quote, ToTokens};
+use syn::spanned::Spanned;
+use syn::{BinOp, Expr, ExprBinary};
+
+use crate::comm::Mutation;
+use crate::transformer::transform_context::TransformContext;
+use crate::transformer::transform_info::SharedTransformInfo;
+
+use crate::MutagenRuntimeConfig;
+
+pub struct MutatorBinopBool {}
+
+impl MutatorBinopBool {
+    pub fn run(
+        mutator_id: usize,
+        left: bool,
+        right: impl FnOnce() -> bool,
+        original_op: BinopBool,
+        runtime: impl Deref<Target = MutagenRuntimeConfig>,
+    ) -> bool {
+        runtime.covered(mutator_id);
+        let mutations = MutationBinopBool::possible_mutations(original_op);
+        if let Some(m) = runtime.get_mutation(mutator_id, &mutations) {
+            m.mutate(left, right)
+        } else {
+            original_op.eval(left, right)
+        }
+    }
+
+    pub fn transform(
+        e: Expr,
+        transform_info: &SharedTransformInfo,
+        context: &TransformContext,
+    ) -> Expr {
+        match e {
+            Expr::Binary(ExprBinary {
+                left,
+                right,
+                op,
+                attrs,
+            }) => {
+                let op = match op {
+                    BinOp::And(t) => BinopBoolSpanned {
+                        op: BinopBool::And,
+                        span: t.into_token_stream().span(),
+                    },
+                    BinOp::Or(t) => BinopBoolSpanned {
+                        op: BinopBool::Or,
+                        span: t.into_token_stream().span(),
+                    },
+                    _ => {
+                        return Expr::Binary(ExprBinary {
+                            left,
+                            right,
+                            op,
+                            attrs,
+                        })
+                    }
+                };
+                let mutator_id = transform_info.add_mutations(
+                    MutationBinopBool::possible_mutations(op.op)
+                        .iter()
+                        .map(|m| m.to_mutation(op, context)),
+                );
+
+                syn::parse2(quote_spanned! {op.span=>
+                    ::mutagen::mutator::MutatorBinopBool::run(
+                            #mutator_id,
+                            #left,
+                            || (#right),
+                            #op,
+                            ::mutagen::MutagenRuntimeConfig::get_default()
+                        )
+                })
+                .expect("transformed code invalid")
+            }
+            _ => e,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct MutationBinopBool {
+    op: BinopBool,
+}
+
+impl MutationBinopBool {
+    fn possible_mutations(original_op: BinopBool) -> Vec<Self> {
+        [BinopBool::And, BinopBool::Or]
+            .iter()
+            .copied()
+            .filter(|&op| op != original_op)
+            .map(|op| MutationBinopBool { op })
+            .collect()
+    }
+
+    fn mutate(self, left: bool, right: impl FnOnce() -> bool) -> bool {
+        self.op.eval(left, right)
+    }
+
+    fn to_mutation(self, original_op: BinopBoolSpanned, context: &TransformContext) -> Mutation {
+        Mutation::new_spanned(
+            context.fn_name.clone(),
+            "binop_bool".to_owned(),
+            format!("{}", original_op),
+            format!("{}", self.op),
+            original_op.span,
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct BinopBoolSpanned {
+    op: BinopBool,
+    span: Span,
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum BinopBool {
+    And,
+    Or,
+}
+
+impl BinopBool {
+    fn eval(self, left: bool, right: impl FnOnce() -> bool) -> bool {
+        match self {
+            BinopBool::And => left && right(),
+            BinopBool::Or => left || right(),
+        }
+    }
+}
+
+impl ToTokens for BinopBoolSpanned {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        // TODO: quote_spanned here
+        tokens.extend(quote!(::mutagen::mutator::mutator_binop_bool::BinopBool::));
+        tokens.extend(match self.op {
+            BinopBool::And => quote_spanned!(self.span=> And),
+            BinopBool::Or => quote_spanned!(self.span=> Or),
+        })
+    }
+}
+
+use std::fmt;
+
+impl fmt::Display for BinopBool {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BinopBool::And => write!(f, "&&"),
+            BinopBool::Or => write!(f, "||"),
+        }
+    }
+}
+
+impl fmt::Display for BinopBoolSpanned {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", &self.op)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn and_inactive() {
+        let result = MutatorBinopBool::run(
+            1,
+            true,
+            || false,
+            BinopBool::And,
+            &MutagenRuntimeConfig::without_mutation(),
+        );
+        assert_eq!(result, false);
+    }
+    #[test]
+    fn and_active() {
+        let result = MutatorBinopBool::run(
+            1,
+            true,
+            || false,
+            BinopBool::And,
+            &MutagenRuntimeConfig::with_mutation_id(1),
+        );
+        assert_eq!(result, true);
+    }
+
+    #[test]
+    fn or_inactive() {
+        let result = MutatorBinopBool::run(
+            1,
+            false,
+            || true,
+            BinopBool::Or,
+            &MutagenRuntimeConfig::without_mutation(),
+        );
+        assert_eq!(result, true);
+    }
+    #[test]
+    fn or_active() {
+        let result = MutatorBinopBool::run(
+            1,
+            false,
+            || true,
+            BinopBool::Or,
+            &MutagenRuntimeConfig::with_mutation_id(1),
+        );
+        assert_eq!(result, false);
+    }
+
+    #[test]
+    fn and_inactive_does_not_evaluate_rhs_when_left_false() {
+        // short-circuit: the right operand must not be evaluated when `left` is false
+        let result = MutatorBinopBool::run(
+            1,
+            false,
+            || panic!("right operand must not be evaluated"),
+            BinopBool::And,
+            &MutagenRuntimeConfig::without_mutation(),
+        );
+        assert_eq!(result, false);
+    }
+}
@@ -0,0 +1,252 @@
+//! Mutator for relational comparison operations `<`, `<=`, `>` and `>=`
+
+use std::ops::Deref;
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote_spanned;
+use quote::{quote, ToTokens};
+use syn::spanned::Spanned;
+use syn::{BinOp, Expr, ExprBinary};
+
+use crate::comm::Mutation;
+use crate::mutator::binop::borrow_operands;
+use crate::transformer::transform_context::TransformContext;
+use crate::transformer::transform_info::SharedTransformInfo;
+
+use crate::MutagenRuntimeConfig;
+
+pub struct MutatorBinopCmp {}
+
+impl MutatorBinopCmp {
+    pub fn run<L: PartialOrd<R>, R>(
+        mutator_id: usize,
+        left: L,
+        right: R,
+        original_op: BinopCmp,
+        runtime: impl Deref<Target = MutagenRuntimeConfig>,
+    ) -> bool {
+        runtime.covered(mutator_id);
+        let mutations = MutationBinopCmp::possible_mutations(original_op);
+        if let Some(m) = runtime.get_mutation(mutator_id, &mutations) {
+            m.mutate(left, right)
+        } else {
+            original_op.cmp(left, right)
+        }
+    }
+
+    pub fn transform(
+        e: Expr,
+        transform_info: &SharedTransformInfo,
+        context: &TransformContext,
+    ) -> Expr {
+        match e {
+            Expr::Binary(ExprBinary {
+                left,
+                right,
+                op,
+                attrs,
+            }) => {
+                let op = match op {
+                    BinOp::Lt(t) => BinopCmpSpanned {
+                        op: BinopCmp::Lt,
+                        span: t.into_token_stream().span(),
+                    },
+                    BinOp::Le(t) => BinopCmpSpanned {
+                        op: BinopCmp::Le,
+                        span: t.into_token_stream().span(),
+                    },
+                    BinOp::Gt(t) => BinopCmpSpanned {
+                        op: BinopCmp::Gt,
+                        span: t.into_token_stream().span(),
+                    },
+                    BinOp::Ge(t) => BinopCmpSpanned {
+                        op: BinopCmp::Ge,
+                        span: t.into_token_stream().span(),
+                    },
+                    _ => {
+                        return Expr::Binary(ExprBinary {
+                            left,
+                            right,
+                            op,
+                            attrs,
+                        })
+                    }
+                };
+                let mutator_id = transform_info.add_mutations(
+                    MutationBinopCmp::possible_mutations(op.op)
+                        .iter()
+                        .map(|m| m.to_mutation(op, context)),
+                );
+
+                let (left, right) = borrow_operands(&left, &right);
+
+                syn::parse2(quote_spanned! {op.span=>
+                    ::mutagen::mutator::MutatorBinopCmp::run(
+                            #mutator_id,
+                            #left,
+                            #right,
+                            #op,
+                            ::mutagen::MutagenRuntimeConfig::get_default()
+                        )
+                })
+                .expect("transformed code invalid")
+            }
+            _ => e,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct MutationBinopCmp {
+    op: BinopCmp,
+}
+
+impl MutationBinopCmp {
+    fn possible_mutations(original_op: BinopCmp) -> Vec<Self> {
+        [BinopCmp::Lt, BinopCmp::Le, BinopCmp::Gt, BinopCmp::Ge]
+            .iter()
+            .copied()
+            .filter(|&op| op != original_op)
+            .map(|op| MutationBinopCmp { op })
+            .collect()
+    }
+
+    fn mutate<L: PartialOrd<R>, R>(self, left: L, right: R) -> bool {
+        self.op.cmp(left, right)
+    }
+
+    fn to_mutation(self, original_op: BinopCmpSpanned, context: &TransformContext) -> Mutation {
+        Mutation::new_spanned(
+            context.fn_name.clone(),
+            "binop_cmp".to_owned(),
+            format!("{}", original_op),
+            format!("{}", self.op),
+            original_op.span,
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct BinopCmpSpanned {
+    op: BinopCmp,
+    span: Span,
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum BinopCmp {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl BinopCmp {
+    fn cmp<L: PartialOrd<R>, R>(self, left: L, right: R) -> bool {
+        match self {
+            BinopCmp::Lt => left < right,
+            BinopCmp::Le => left <= right,
+            BinopCmp::Gt => left > right,
+            BinopCmp::Ge => left >= right,
+        }
+    }
+}
+
+impl ToTokens for BinopCmpSpanned {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        // TODO: quote_spanned here
+        tokens.extend(quote!(::mutagen::mutator::mutator_binop_cmp::BinopCmp::));
+        tokens.extend(match self.op {
+            BinopCmp::Lt => quote_spanned!(self.span=> Lt),
+            BinopCmp::Le => quote_spanned!(self.span=> Le),
+            BinopCmp::Gt => quote_spanned!(self.span=> Gt),
+            BinopCmp::Ge => quote_spanned!(self.span=> Ge),
+        })
+    }
+}
+
+use std::fmt;
+
+impl fmt::Display for BinopCmp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BinopCmp::Lt => write!(f, "<"),
+            BinopCmp::Le => write!(f, "<="),
+            BinopCmp::Gt => write!(f, ">"),
+            BinopCmp::Ge => write!(f, ">="),
+        }
+    }
+}
+
+impl fmt::Display for BinopCmpSpanned {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", &self.op)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn lt_inactive() {
+        let result = MutatorBinopCmp::run(
+            1,
+            4,
+            5,
+            BinopCmp::Lt,
+            &MutagenRuntimeConfig::without_mutation(),
+        );
+        assert_eq!(result, true);
+    }
+    #[test]
+    fn lt_active() {
+        // `<` mutated to the first alternative operator `<=`
+        let result = MutatorBinopCmp::run(
+            1,
+            5,
+            5,
+            BinopCmp::Lt,
+            &MutagenRuntimeConfig::with_mutation_id(1),
+        );
+        assert_eq!(result, true);
+    }
+
+    #[test]
+    fn ge_inactive() {
+        let result = MutatorBinopCmp::run(
+            1,
+            5,
+            5,
+            BinopCmp::Ge,
+            &MutagenRuntimeConfig::without_mutation(),
+        );
+        assert_eq!(result, true);
+    }
+
+    #[test]
+    fn ge_active() {
+        // `>=` mutated to the first alternative operator `<`
+        let result = MutatorBinopCmp::run(
+            1,
+            5,
+            5,
+            BinopCmp::Ge,
+            &MutagenRuntimeConfig::with_mutation_id(1),
+        );
+        assert_eq!(result, false);
+    }
+
+    #[test]
+    fn nan_inactive() {
+        // comparisons against NaN are well-defined: every operator returns false
+        let result = MutatorBinopCmp::run(
+            1,
+            f64::NAN,
+            5.0,
+            BinopCmp::Lt,
+            &MutagenRuntimeConfig::without_mutation(),
+        );
+        assert_eq!(result, false);
+    }
+}
@@ -9,6 +9,7 @@ use syn::spanned::Spanned;
 use syn::{BinOp, Expr, ExprBinary};
 
 use crate::comm::Mutation;
+use crate::mutator::binop::borrow_operands;
 use crate::transformer::transform_context::TransformContext;
 use crate::transformer::transform_info::SharedTransformInfo;
 
@@ -22,10 +23,11 @@ impl MutatorBinopEq {
         left: L,
         right: R,
         original_op: BinopEq,
+        constant_result_mutations: bool,
         runtime: impl Deref<Target = MutagenRuntimeConfig>,
     ) -> bool {
         runtime.covered(mutator_id);
-        let mutations = MutationBinopEq::possible_mutations(original_op);
+        let mutations = MutationBinopEq::possible_mutations(original_op, constant_result_mutations);
         if let Some(m) = runtime.get_mutation(mutator_id, &mutations) {
             m.mutate(left, right)
         } else {
@@ -63,18 +65,28 @@ impl MutatorBinopEq {
                         })
                     }
                 };
+                // Decide the mutation set once, here at transform time, and bake the
+                // decision into the generated call. The count of registered mutations
+                // must not depend on anything the run-time process could observe
+                // differently (e.g. a mutable env var), or the mutation ids reserved
+                // here would not line up with the ones `run` reconstructs.
+                let constant_result_mutations =
+                    MutagenRuntimeConfig::get_default().constant_result_mutations();
                 let mutator_id = transform_info.add_mutations(
-                    MutationBinopEq::possible_mutations(op.op)
+                    MutationBinopEq::possible_mutations(op.op, constant_result_mutations)
                         .iter()
                         .map(|m| m.to_mutation(op, context)),
                 );
 
+                let (left, right) = borrow_operands(&left, &right);
+
                 syn::parse2(quote_spanned! {op.span=>
                     ::mutagen::mutator::MutatorBinopEq::run(
                             #mutator_id,
-                            &(#left),
-                            &(#right),
+                            #left,
+                            #right,
                             #op,
+                            #constant_result_mutations,
                             ::mutagen::MutagenRuntimeConfig::get_default()
                         )
                 })
@@ -86,22 +98,39 @@ impl MutatorBinopEq {
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
-struct MutationBinopEq {
-    op: BinopEq,
+enum MutationBinopEq {
+    // swap `==` <-> `!=`
+    Op(BinopEq),
+    // collapse the whole comparison to a constant, independent of the operands
+    Const(bool),
 }
 
 impl MutationBinopEq {
-    fn possible_mutations(original_op: BinopEq) -> Vec<Self> {
-        [BinopEq::Eq, BinopEq::Ne]
+    fn possible_mutations(original_op: BinopEq, constant_result_mutations: bool) -> Vec<Self> {
+        let mut mutations: Vec<Self> = [BinopEq::Eq, BinopEq::Ne]
             .iter()
             .copied()
             .filter(|&op| op != original_op)
-            .map(|op| MutationBinopEq { op })
-            .collect()
+            .map(MutationBinopEq::Op)
+            .collect();
+        // the classic constant-replacement operator, opt-in via the runtime config
+        if constant_result_mutations {
+            mutations.push(MutationBinopEq::Const(true));
+            mutations.push(MutationBinopEq::Const(false));
+        }
+        mutations
     }
 
     fn mutate<L: PartialEq<R>, R>(self, left: L, right: R) -> bool {
-        self.op.eq(left, right)
+        match self {
+            MutationBinopEq::Op(op) => op.eq(left, right),
+            MutationBinopEq::Const(c) => {
+                // the operands were already evaluated at the call site, so their side
+                // effects have run as in the original expression; they are discarded
+                let _ = (left, right);
+                c
+            }
+        }
     }
 
     fn to_mutation(self, original_op: BinopEqSpanned, context: &TransformContext) -> Mutation {
@@ -109,10 +138,17 @@ impl MutationBinopEq {
             context.fn_name.clone(),
             "binop_eq".to_owned(),
             format!("{}", original_op),
-            format!("{}", self.op),
+            self.mutation_description(),
             original_op.span,
         )
     }
+
+    fn mutation_description(self) -> String {
+        match self {
+            MutationBinopEq::Op(op) => format!("{}", op),
+            MutationBinopEq::Const(c) => format!("{}", c),
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -176,6 +212,7 @@ mod tests {
             5,
             4,
             BinopEq::Eq,
+            false,
             &MutagenRuntimeConfig::without_mutation(),
         );
         assert_eq!(result, false);
@@ -187,6 +224,7 @@ mod tests {
             5,
             4,
             BinopEq::Eq,
+            false,
             &MutagenRuntimeConfig::with_mutation_id(1),
         );
         assert_eq!(result, true);
@@ -199,6 +237,7 @@ mod tests {
             5,
             4,
             BinopEq::Ne,
+            false,
             &MutagenRuntimeConfig::without_mutation(),
         );
         assert_eq!(result, true);
@@ -210,8 +249,174 @@ mod tests {
             5,
             4,
             BinopEq::Ne,
+            false,
             &MutagenRuntimeConfig::with_mutation_id(1),
         );
         assert_eq!(result, false);
     }
+
+    // constant-result ("collapse") mutations, enabled when the transform baked in
+    // `constant_result_mutations = true`. With the flag set, `possible_mutations`
+    // for `==` is `[Op(!=), Const(true), Const(false)]`, so mutation ids 2 and 3
+    // (relative to `mutator_id` 1) select the two constants.
+
+    #[test]
+    fn eq_collapse_to_true() {
+        let result = MutatorBinopEq::run(
+            1,
+            5,
+            4,
+            BinopEq::Eq,
+            true,
+            &MutagenRuntimeConfig::with_mutation_id(2),
+        );
+        assert_eq!(result, true);
+    }
+    #[test]
+    fn eq_collapse_to_false() {
+        let result = MutatorBinopEq::run(
+            1,
+            5,
+            5,
+            BinopEq::Eq,
+            true,
+            &MutagenRuntimeConfig::with_mutation_id(3),
+        );
+        assert_eq!(result, false);
+    }
+
+    #[test]
+    fn collapse_still_evaluates_operands() {
+        // the operands are evaluated at the call site, so their side effects run
+        // even when the comparison collapses to a constant
+        let mut right_evaluated = false;
+        let result = MutatorBinopEq::run(
+            1,
+            5,
+            {
+                right_evaluated = true;
+                4
+            },
+            BinopEq::Eq,
+            true,
+            &MutagenRuntimeConfig::with_mutation_id(2),
+        );
+        assert_eq!(result, true);
+        assert!(right_evaluated);
+    }
+
+    #[test]
+    fn collapse_mutations_absent_when_flag_off() {
+        // without the flag, only the operator swap is registered, so the ids that
+        // would select a constant fall outside the range and leave the comparison
+        // unmutated
+        let result = MutatorBinopEq::run(
+            1,
+            5,
+            4,
+            BinopEq::Eq,
+            false,
+            &MutagenRuntimeConfig::with_mutation_id(2),
+        );
+        assert_eq!(result, false);
+    }
+
+    // Heterogeneous comparisons must keep compiling and behave like the bare
+    // `==`. These drive `run` with operands borrowed exactly as `transform`
+    // emits them (wrap-both for plain operands, forward-as-is for references).
+
+    fn defaulted<T: Default>() -> T {
+        T::default()
+    }
+
+    #[test]
+    fn heterogeneous_str_vs_string() {
+        // `string == "foo"`: wrapped to `run(&String, &&str)`
+        let s = String::from("foo");
+        let result = MutatorBinopEq::run(
+            1,
+            &s,
+            &"foo",
+            BinopEq::Eq,
+            false,
+            &MutagenRuntimeConfig::without_mutation(),
+        );
+        assert_eq!(result, true);
+    }
+
+    #[test]
+    fn heterogeneous_vec_vs_array() {
+        // `vec == ["a", "b"]`: wrapped to `run(&Vec<&str>, &[&str; 2])`
+        let v = vec!["a", "b"];
+        let result = MutatorBinopEq::run(
+            1,
+            &v,
+            &["a", "b"],
+            BinopEq::Eq,
+            false,
+            &MutagenRuntimeConfig::without_mutation(),
+        );
+        assert_eq!(result, true);
+    }
+
+    #[test]
+    fn heterogeneous_generic_rhs_infers() {
+        // `lhs == some_generic_fn()`: wrapping both sides is inference-transparent.
+        // Via `impl PartialEq<&B> for &A` the bound reduces to `i32: PartialEq<T>`,
+        // so `T` still infers to `i32` exactly as the bare `==` would - the call
+        // compiles without a turbofish on `defaulted`.
+        let lhs = 0i32;
+        let result = MutatorBinopEq::run(
+            1,
+            &lhs,
+            &defaulted(),
+            BinopEq::Eq,
+            false,
+            &MutagenRuntimeConfig::without_mutation(),
+        );
+        assert_eq!(result, true);
+    }
+
+    #[test]
+    fn heterogeneous_mut_ref_vs_shared_ref() {
+        // `&mut x == &y`: both operands are already references, so `transform`
+        // forwards them untouched and `run` sees the exact source types, compared
+        // via `core`'s `impl PartialEq<&B> for &mut A`.
+        let mut x = 5;
+        let y = 5;
+        let result = MutatorBinopEq::run(
+            1,
+            &mut x,
+            &y,
+            BinopEq::Eq,
+            false,
+            &MutagenRuntimeConfig::without_mutation(),
+        );
+        assert_eq!(result, true);
+    }
+
+    // `borrow_operands` itself keeps both sides at the same ref-level; assert its
+    // token output for the cases that are awkward to exercise as live types.
+
+    use syn::parse_quote;
+
+    #[test]
+    fn borrow_operands_wraps_reference_typed_variable_symmetrically() {
+        // `x == &y` where `x` is a reference-typed variable: `x` is a plain path,
+        // so both sides are wrapped, staying at the same ref-level (the baseline
+        // `&&i32 == &&i32` that compiled). Wrapping only `x` would produce the
+        // impossible `&&i32 == &i32`.
+        let (left, right) = borrow_operands(&parse_quote!(x), &parse_quote!(&y));
+        assert_eq!(left.to_string(), quote!(&(x)).to_string());
+        assert_eq!(right.to_string(), quote!(&(&y)).to_string());
+    }
+
+    #[test]
+    fn borrow_operands_forwards_mixed_references_untouched() {
+        // `&mut x == &y`: both operands are already references and are forwarded
+        // as-is, so the comparison sees exactly the source types.
+        let (left, right) = borrow_operands(&parse_quote!(&mut x), &parse_quote!(&y));
+        assert_eq!(left.to_string(), quote!(&mut x).to_string());
+        assert_eq!(right.to_string(), quote!(&y).to_string());
+    }
 }
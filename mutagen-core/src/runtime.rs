@@ -0,0 +1,80 @@
+//! Runtime configuration consulted by the generated mutator calls.
+
+use std::env;
+use std::ops::Deref;
+use std::sync::{Mutex, MutexGuard, OnceLock};
+
+/// Configuration that selects the active mutation (if any) at runtime and
+/// controls which optional mutations the transformers emit.
+#[derive(Clone, Debug)]
+pub struct MutagenRuntimeConfig {
+    mutation_id: usize,
+    constant_result_mutations: bool,
+}
+
+static RUNTIME_CONFIG: OnceLock<Mutex<MutagenRuntimeConfig>> = OnceLock::new();
+
+impl MutagenRuntimeConfig {
+    /// The globally shared configuration, initialized from the environment on
+    /// first access.
+    pub fn get_default() -> impl Deref<Target = Self> {
+        RUNTIME_CONFIG
+            .get_or_init(|| Mutex::new(MutagenRuntimeConfig::from_env()))
+            .lock()
+            .expect("mutagen runtime config poisoned")
+    }
+
+    fn from_env() -> Self {
+        let mutation_id = env::var("MUTATION_ID")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(0);
+        let constant_result_mutations = env::var("MUTAGEN_CONSTANT_RESULT_MUTATIONS")
+            .map(|s| s == "1" || s == "true")
+            .unwrap_or(false);
+        Self {
+            mutation_id,
+            constant_result_mutations,
+        }
+    }
+
+    /// Called by every mutator when it is reached; this configuration does not
+    /// record coverage, so the call has no effect.
+    pub fn covered(&self, _mutator_id: usize) {}
+
+    /// Return the mutation from `mutations` selected by the active mutation id,
+    /// or `None` when no mutation of this mutator is active.
+    pub fn get_mutation<M: Clone>(&self, mutator_id: usize, mutations: &[M]) -> Option<M> {
+        self.mutation_id
+            .checked_sub(mutator_id)
+            .and_then(|index| mutations.get(index))
+            .cloned()
+    }
+
+    /// Whether the opt-in constant-result mutations (collapsing a comparison to
+    /// a literal `true`/`false`) should be offered in addition to the plain
+    /// operator swaps.
+    pub fn constant_result_mutations(&self) -> bool {
+        self.constant_result_mutations
+    }
+}
+
+/// Test helpers for constructing configurations without touching the
+/// environment.
+impl MutagenRuntimeConfig {
+    /// A configuration with no active mutation.
+    pub fn without_mutation() -> Self {
+        Self {
+            mutation_id: 0,
+            constant_result_mutations: false,
+        }
+    }
+
+    /// A configuration with the given mutation id active.
+    pub fn with_mutation_id(mutation_id: usize) -> Self {
+        Self {
+            mutation_id,
+            constant_result_mutations: false,
+        }
+    }
+}